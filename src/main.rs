@@ -3,7 +3,7 @@ use clap::{Parser, Subcommand};
 /// Simple CLI for managing Ethereum accounts
 #[derive(Parser)]
 #[command(name = "eth-account-manager")]
-#[command(about = "Manage your Ethereum accounts", long_about = None)]
+#[command(version, about = "Manage your Ethereum accounts", long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,